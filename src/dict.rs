@@ -0,0 +1,149 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use smallvec::SmallVec;
+
+fn hash_str(s: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    s.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Deduplicating string arena: every unique byte sequence interned through
+/// `intern`/`intern_range` is stored exactly once, and is referred to
+/// everywhere else by its compact `u32` atom id instead of an owned
+/// `String`.
+#[derive(Debug, Default)]
+struct AtomTable {
+    arena: String,
+    spans: Vec<(u32, u32)>,
+    index: HashMap<u64, SmallVec<[u32; 1]>>,
+}
+
+impl AtomTable {
+    fn as_str(&self, id: u32) -> &str {
+        let (start, end) = self.spans[id as usize];
+        &self.arena[start as usize..end as usize]
+    }
+
+    fn get(&self, s: &str) -> Option<u32> {
+        let h = hash_str(s);
+        self.index.get(&h)?.iter().copied().find(|&id| self.as_str(id) == s)
+    }
+
+    fn push(&mut self, start: u32, end: u32) -> u32 {
+        let id = self.spans.len() as u32;
+        self.spans.push((start, end));
+        let h = hash_str(&self.arena[start as usize..end as usize]);
+        self.index.entry(h).or_default().push(id);
+        id
+    }
+
+    /// Interns `s`, copying its bytes into the arena only if not already
+    /// present.
+    fn intern(&mut self, s: &str) -> u32 {
+        if let Some(id) = self.get(s) {
+            return id;
+        }
+        let start = self.arena.len() as u32;
+        self.arena.push_str(s);
+        self.push(start, self.arena.len() as u32)
+    }
+
+    /// Interns the arena range `[start, end)`, which must already be a
+    /// valid char-boundary slice of `self.arena` (as produced by a prior
+    /// `intern`/`intern_range` call). Used to register a word's own
+    /// prefixes without copying bytes that are already there.
+    fn intern_range(&mut self, start: u32, end: u32) -> u32 {
+        if let Some(id) = self.get(&self.arena[start as usize..end as usize]) {
+            return id;
+        }
+        self.push(start, end)
+    }
+}
+
+/// Frequency table keyed by interned atom id rather than by owned `String`.
+///
+/// Replaces the `Trie<String, usize>` this crate used to key every word
+/// *and* every prefix fragment by its own heap-allocated `String` (the
+/// "double hashing" `load_dict` used to complain about): a word's prefix
+/// fragments now reuse the byte range already written for the word they
+/// were sliced from instead of each allocating an independent copy, and
+/// lookups hash the queried `&str` directly rather than requiring an owned
+/// key.
+#[derive(Debug, Default)]
+pub(crate) struct FreqDict {
+    atoms: AtomTable,
+    freqs: Vec<Option<usize>>,
+}
+
+impl FreqDict {
+    fn ensure_slot(&mut self, id: u32) {
+        let id = id as usize;
+        if self.freqs.len() <= id {
+            self.freqs.resize(id + 1, None);
+        }
+    }
+
+    /// Inserts `word` with `freq`, returning its atom id so callers can
+    /// derive its arena span for `insert_prefix`.
+    pub(crate) fn insert(&mut self, word: &str, freq: usize) -> u32 {
+        let id = self.atoms.intern(word);
+        self.ensure_slot(id);
+        self.freqs[id as usize] = Some(freq);
+        id
+    }
+
+    /// Registers the `[start, end)` arena range of a previously interned
+    /// word as a known prefix fragment, with frequency 0 unless it is
+    /// already present with a real frequency.
+    pub(crate) fn insert_prefix(&mut self, start: u32, end: u32) {
+        let id = self.atoms.intern_range(start, end);
+        self.ensure_slot(id);
+        self.freqs[id as usize].get_or_insert(0);
+    }
+
+    /// The arena span of `word`'s atom, as returned by `insert`.
+    pub(crate) fn span(&self, id: u32) -> (u32, u32) {
+        self.atoms.spans[id as usize]
+    }
+
+    pub(crate) fn get(&self, word: &str) -> Option<&usize> {
+        let id = self.atoms.get(word)?;
+        self.freqs[id as usize].as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FreqDict;
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut dict = FreqDict::default();
+        dict.insert("网球拍卖会", 10);
+        assert_eq!(dict.get("网球拍卖会"), Some(&10));
+        assert_eq!(dict.get("网球"), None);
+    }
+
+    #[test]
+    fn test_prefix_shares_storage_with_word() {
+        let mut dict = FreqDict::default();
+        let id = dict.insert("网球拍卖会", 10);
+        let (start, _) = dict.span(id);
+        dict.insert_prefix(start, start + "网球".len() as u32);
+        assert_eq!(dict.get("网球"), Some(&0));
+        assert_eq!(dict.get("网球拍卖会"), Some(&10));
+    }
+
+    #[test]
+    fn test_prefix_never_overwrites_real_freq() {
+        let mut dict = FreqDict::default();
+        let id = dict.insert("网球拍卖会", 10);
+        let (start, _) = dict.span(id);
+        dict.insert_prefix(start, start + "网球".len() as u32);
+        dict.insert("网球", 5);
+        assert_eq!(dict.get("网球"), Some(&5));
+    }
+}
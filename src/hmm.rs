@@ -0,0 +1,219 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+static PROB_START_DATA: &str = include_str!("data/prob_start.txt");
+static PROB_TRANS_DATA: &str = include_str!("data/prob_trans.txt");
+static PROB_EMIT_DATA: &str = include_str!("data/prob_emit.txt");
+
+const MIN_FLOAT: f64 = -3.14e100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Status {
+    B,
+    M,
+    E,
+    S,
+}
+
+const STATUSES: [Status; 4] = [Status::B, Status::M, Status::E, Status::S];
+
+fn parse_start(data: &str) -> HashMap<Status, f64> {
+    let mut start = HashMap::new();
+    for line in data.lines() {
+        let parts: Vec<&str> = line.trim().split(' ').collect();
+        let status = match parts[0] {
+            "B" => Status::B,
+            "E" => Status::E,
+            "M" => Status::M,
+            "S" => Status::S,
+            _ => continue,
+        };
+        start.insert(status, parts[1].parse().unwrap());
+    }
+    start
+}
+
+fn parse_trans(data: &str) -> HashMap<(Status, Status), f64> {
+    let mut trans = HashMap::new();
+    for line in data.lines() {
+        let parts: Vec<&str> = line.trim().split(' ').collect();
+        let from = match parts[0] {
+            "B" => Status::B,
+            "E" => Status::E,
+            "M" => Status::M,
+            "S" => Status::S,
+            _ => continue,
+        };
+        let to = match parts[1] {
+            "B" => Status::B,
+            "E" => Status::E,
+            "M" => Status::M,
+            "S" => Status::S,
+            _ => continue,
+        };
+        trans.insert((from, to), parts[2].parse().unwrap());
+    }
+    trans
+}
+
+fn parse_emit(data: &str) -> HashMap<(Status, char), f64> {
+    let mut emit = HashMap::new();
+    for line in data.lines() {
+        let parts: Vec<&str> = line.trim().split(' ').collect();
+        let status = match parts[0] {
+            "B" => Status::B,
+            "E" => Status::E,
+            "M" => Status::M,
+            "S" => Status::S,
+            _ => continue,
+        };
+        let chr = parts[1].chars().next().unwrap();
+        emit.insert((status, chr), parts[2].parse().unwrap());
+    }
+    emit
+}
+
+lazy_static! {
+    static ref PROB_START: HashMap<Status, f64> = parse_start(PROB_START_DATA);
+    static ref PROB_TRANS: HashMap<(Status, Status), f64> = parse_trans(PROB_TRANS_DATA);
+    static ref PROB_EMIT: HashMap<(Status, char), f64> = parse_emit(PROB_EMIT_DATA);
+}
+
+/// Runs the Viterbi algorithm over an arbitrary tag set, returning the most
+/// likely tag for every character in `chars`.
+///
+/// This is the shared core behind the word-segmentation fallback in
+/// `cut` below as well as the entity/POS taggers layered on top of it; only
+/// the tag set and the three probability tables change between callers.
+pub(crate) fn viterbi<T>(
+    chars: &[char],
+    tags: &[T],
+    start: &HashMap<T, f64>,
+    trans: &HashMap<(T, T), f64>,
+    emit: &HashMap<(T, char), f64>,
+) -> Vec<T>
+where
+    T: Copy + Eq + Hash,
+{
+    let word_count = chars.len();
+    if word_count == 0 {
+        return Vec::new();
+    }
+
+    let mut delta: Vec<HashMap<T, f64>> = Vec::with_capacity(word_count);
+    let mut back: Vec<HashMap<T, T>> = Vec::with_capacity(word_count);
+
+    let mut first = HashMap::new();
+    for &tag in tags {
+        let e = *emit.get(&(tag, chars[0])).unwrap_or(&MIN_FLOAT);
+        first.insert(tag, start.get(&tag).copied().unwrap_or(MIN_FLOAT) + e);
+    }
+    delta.push(first);
+    back.push(HashMap::new());
+
+    for t in 1..word_count {
+        let mut scores = HashMap::new();
+        let mut ptrs = HashMap::new();
+        for &tag in tags {
+            let e = *emit.get(&(tag, chars[t])).unwrap_or(&MIN_FLOAT);
+            let (best_prev, best_score) = tags
+                .iter()
+                .map(|&prev| {
+                    let trans_p = trans.get(&(prev, tag)).copied().unwrap_or(MIN_FLOAT);
+                    (prev, delta[t - 1][&prev] + trans_p)
+                })
+                .max_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal))
+                .unwrap();
+            scores.insert(tag, best_score + e);
+            ptrs.insert(tag, best_prev);
+        }
+        delta.push(scores);
+        back.push(ptrs);
+    }
+
+    let last = &delta[word_count - 1];
+    let mut tag = *tags
+        .iter()
+        .max_by(|&&x, &&y| last[&x].partial_cmp(&last[&y]).unwrap_or(Ordering::Equal))
+        .unwrap();
+
+    let mut path = vec![tag; word_count];
+    for t in (1..word_count).rev() {
+        tag = back[t][&tag];
+        path[t - 1] = tag;
+    }
+    path
+}
+
+/// Like `cut`, but returns half-open `[start, end)` *character* ranges
+/// instead of owned `String`s, so callers that already hold a borrowed
+/// slice of the source text can reslice it instead of allocating.
+pub(crate) fn cut_indices(chars: &[char]) -> Vec<(usize, usize)> {
+    let path = viterbi(chars, &STATUSES, &PROB_START, &PROB_TRANS, &PROB_EMIT);
+
+    let mut ranges = Vec::new();
+    let mut open: Option<usize> = None;
+    for (i, &status) in path.iter().enumerate() {
+        match status {
+            Status::B => {
+                if let Some(start) = open {
+                    ranges.push((start, i));
+                }
+                open = Some(i);
+            }
+            Status::M => {
+                open.get_or_insert(i);
+            }
+            Status::E => ranges.push((open.take().unwrap_or(i), i + 1)),
+            Status::S => {
+                if let Some(start) = open.take() {
+                    ranges.push((start, i));
+                }
+                ranges.push((i, i + 1));
+            }
+        }
+    }
+    if let Some(start) = open {
+        ranges.push((start, chars.len()));
+    }
+    ranges
+}
+
+/// Segments a run of characters with no dictionary coverage using the
+/// default word-segmentation HMM (BMES tagging).
+pub(crate) fn cut(sentence: &str) -> Vec<String> {
+    let chars: Vec<char> = sentence.chars().collect();
+    let path = viterbi(&chars, &STATUSES, &PROB_START, &PROB_TRANS, &PROB_EMIT);
+
+    let mut words = Vec::new();
+    let mut buf = String::new();
+    for (&chr, &status) in chars.iter().zip(path.iter()) {
+        match status {
+            Status::B => {
+                if !buf.is_empty() {
+                    words.push(buf.clone());
+                    buf.clear();
+                }
+                buf.push(chr);
+            }
+            Status::M => buf.push(chr),
+            Status::E => {
+                buf.push(chr);
+                words.push(buf.clone());
+                buf.clear();
+            }
+            Status::S => {
+                if !buf.is_empty() {
+                    words.push(buf.clone());
+                    buf.clear();
+                }
+                words.push(chr.to_string());
+            }
+        }
+    }
+    if !buf.is_empty() {
+        words.push(buf);
+    }
+    words
+}
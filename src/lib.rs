@@ -1,4 +1,3 @@
-extern crate radix_trie;
 extern crate smallvec;
 extern crate regex;
 #[macro_use]
@@ -6,14 +5,20 @@ extern crate lazy_static;
 extern crate phf;
 
 use std::io::{self, BufRead, BufReader};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::cmp::Ordering;
 
 use regex::{Regex, Captures, CaptureMatches};
-use radix_trie::Trie;
 use smallvec::SmallVec;
 
+mod dict;
 mod hmm;
+mod ner;
+mod postag;
+
+use dict::FreqDict;
+
+pub use ner::{Entity, EntityKind, Ner};
 
 static DEFAULT_DICT: &str = include_str!("data/dict.txt");
 
@@ -87,12 +92,119 @@ impl<'r, 't> Iterator for SplitCaptures<'r, 't> {
     }
 }
 
+/// Pushes each character of `block` as its own slice of `block`.
+fn push_non_han<'a>(block: &'a str, queue: &mut VecDeque<&'a str>) {
+    for (i, chr) in block.char_indices() {
+        queue.push_back(&block[i..i + chr.len_utf8()]);
+    }
+}
+
+/// Lazy, borrowing tokenization iterator returned by `Jieba::cut_iter`.
+///
+/// Tokens are produced on demand: each call to `next` advances the
+/// underlying Han/non-Han regex split only far enough to refill a small
+/// per-block work queue, rather than segmenting the whole sentence up
+/// front like `cut` does.
+pub struct Cut<'a> {
+    jieba: &'a Jieba,
+    sentence: &'a str,
+    finder: CaptureMatches<'static, 'a>,
+    last: usize,
+    done: bool,
+    hmm: bool,
+    queue: VecDeque<&'a str>,
+}
+
+impl<'a> Cut<'a> {
+    fn process_block(&mut self, block: &'a str, is_han: bool) {
+        if block.is_empty() {
+            return;
+        }
+        if is_han {
+            if self.hmm {
+                self.jieba.cut_dag_hmm_ranges(block, &mut self.queue);
+            } else {
+                self.jieba.cut_dag_no_hmm_ranges(block, &mut self.queue);
+            }
+        } else {
+            let mut last = 0;
+            for caps in RE_SKIP_DEAFULT.captures_iter(block) {
+                let m = caps.get(0).unwrap();
+                push_non_han(&block[last..m.start()], &mut self.queue);
+                self.queue.push_back(&block[m.start()..m.end()]);
+                last = m.end();
+            }
+            push_non_han(&block[last..], &mut self.queue);
+        }
+    }
+}
+
+impl<'a> Iterator for Cut<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        loop {
+            if let Some(word) = self.queue.pop_front() {
+                return Some(word);
+            }
+            if self.done {
+                return None;
+            }
+            match self.finder.next() {
+                Some(caps) => {
+                    let m = caps.get(0).unwrap();
+                    let unmatched = &self.sentence[self.last..m.start()];
+                    let matched = &self.sentence[m.start()..m.end()];
+                    self.last = m.end();
+                    self.process_block(unmatched, false);
+                    self.process_block(matched, true);
+                }
+                None => {
+                    self.done = true;
+                    if self.last < self.sentence.len() {
+                        let tail = &self.sentence[self.last..];
+                        self.last = self.sentence.len();
+                        self.process_block(tail, false);
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Jieba {
-    freq: Trie<String, usize>,
+    freq: FreqDict,
+    tags: HashMap<String, String>,
     total: usize
 }
 
+/// A `(word, tag)` pair produced by [`Jieba::tag`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub word: String,
+    pub tag: String,
+}
+
+/// Selects how thoroughly [`Jieba::tokenize`] decomposes long words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenizeMode {
+    /// One token per segmented word.
+    Default,
+    /// Like `Default`, but also emits the dictionary-covered sub-spans of
+    /// every word longer than two characters, for search indexing.
+    Search,
+}
+
+/// A token produced by [`Jieba::tokenize`], with exact UTF-8 byte offsets
+/// into the sentence it was cut from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub word: &'a str,
+    pub start: usize,
+    pub end: usize,
+}
+
 impl Default for Jieba {
     fn default() -> Self {
         Jieba::new()
@@ -102,7 +214,8 @@ impl Default for Jieba {
 impl Jieba {
     pub fn new() -> Self {
         let mut instance = Jieba {
-            freq: Trie::new(),
+            freq: FreqDict::default(),
+            tags: HashMap::new(),
             total: 0
         };
         let mut default_dict = BufReader::new(DEFAULT_DICT.as_bytes());
@@ -110,6 +223,9 @@ impl Jieba {
         instance
     }
 
+    /// Loads a dictionary of `word freq` or `word freq pos` lines. The
+    /// trailing `pos` column is optional so dictionaries without tags keep
+    /// working unchanged.
     pub fn load_dict<R: BufRead>(&mut self, dict: &mut R) -> io::Result<()> {
         let mut buf = String::new();
         let mut total = 0;
@@ -119,15 +235,14 @@ impl Jieba {
                 let word = parts[0];
                 let freq: usize = parts[1].parse().unwrap();
                 total += freq;
-                self.freq.insert(word.to_string(), freq);
+                let id = self.freq.insert(word, freq);
+                if let Some(&tag) = parts.get(2) {
+                    self.tags.insert(word.to_string(), tag.to_string());
+                }
+                let (start, _) = self.freq.span(id);
                 let char_indices: Vec<usize> = word.char_indices().map(|x| x.0).collect();
-                for i in 1..char_indices.len() {
-                    let index = char_indices[i];
-                    let wfrag = &word[0..index];
-                    // XXX: this will do double hashing, should be avoided
-                    if self.freq.get(wfrag).is_none() {
-                        self.freq.insert(wfrag.to_string(), 0);
-                    }
+                for &index in &char_indices[1..] {
+                    self.freq.insert_prefix(start, start + index as u32);
                 }
             }
             buf.clear();
@@ -306,6 +421,181 @@ impl Jieba {
         words
     }
 
+    /// Byte range of the `i`th character, given its `char_indices`; `end`
+    /// is derived from the next character's start, or the string's end.
+    fn char_byte_range(sentence: &str, char_indices: &[(usize, char)], i: usize) -> (usize, usize) {
+        let start = char_indices[i].0;
+        let end = if i + 1 < char_indices.len() {
+            char_indices[i + 1].0
+        } else {
+            sentence.len()
+        };
+        (start, end)
+    }
+
+    /// Borrowing counterpart of `cut_dag_no_hmm`: pushes `&'a str` slices of
+    /// `sentence` into `queue` instead of allocating a `String` per token.
+    fn cut_dag_no_hmm_ranges<'a>(&self, sentence: &'a str, queue: &mut VecDeque<&'a str>) {
+        let char_indices: Vec<(usize, char)> = sentence.char_indices().collect();
+        let dag = self.dag(sentence, &char_indices);
+        let route = self.calc(sentence, &char_indices, &dag);
+        let mut x = 0;
+        let mut run_start: Option<usize> = None;
+        while x < char_indices.len() {
+            let y = route[x].1 + 1;
+            let l_indices = &char_indices[x..y];
+            let (seg_start, seg_end) = (
+                char_indices[x].0,
+                if y < char_indices.len() { char_indices[y].0 } else { sentence.len() },
+            );
+            if l_indices.len() == 1 && l_indices.iter().all(|ch| ch.1.is_ascii_alphanumeric()) {
+                if run_start.is_none() {
+                    run_start = Some(seg_start);
+                }
+            } else {
+                if let Some(s) = run_start.take() {
+                    queue.push_back(&sentence[s..seg_start]);
+                }
+                queue.push_back(&sentence[seg_start..seg_end]);
+            }
+            x = y;
+        }
+        if let Some(s) = run_start.take() {
+            queue.push_back(&sentence[s..sentence.len()]);
+        }
+    }
+
+    /// Flushes a run of `char_indices[cs..ce]` accumulated by
+    /// `cut_dag_hmm_ranges`, following the exact same rules as the `buf`
+    /// handling in `cut_dag_hmm`: lone characters are pushed as-is, known
+    /// dictionary runs are split into individual characters, and
+    /// out-of-vocabulary runs are refined by the HMM.
+    fn flush_hmm_range<'a>(
+        &self,
+        sentence: &'a str,
+        char_indices: &[(usize, char)],
+        run: Option<(usize, usize)>,
+        queue: &mut VecDeque<&'a str>,
+    ) {
+        let (cs, ce) = match run {
+            Some(r) => r,
+            None => return,
+        };
+        if ce - cs == 1 {
+            let (start, end) = Self::char_byte_range(sentence, char_indices, cs);
+            queue.push_back(&sentence[start..end]);
+            return;
+        }
+        let start = char_indices[cs].0;
+        let end = if ce < char_indices.len() { char_indices[ce].0 } else { sentence.len() };
+        let wfrag = &sentence[start..end];
+        if self.freq.get(wfrag).is_none() {
+            let chars: Vec<char> = char_indices[cs..ce].iter().map(|&(_, c)| c).collect();
+            for (rs, re) in hmm::cut_indices(&chars) {
+                let start = char_indices[cs + rs].0;
+                let end = if cs + re < char_indices.len() {
+                    char_indices[cs + re].0
+                } else {
+                    sentence.len()
+                };
+                queue.push_back(&sentence[start..end]);
+            }
+        } else {
+            for i in cs..ce {
+                let (s, e) = Self::char_byte_range(sentence, char_indices, i);
+                queue.push_back(&sentence[s..e]);
+            }
+        }
+    }
+
+    /// Borrowing counterpart of `cut_dag_hmm`.
+    fn cut_dag_hmm_ranges<'a>(&self, sentence: &'a str, queue: &mut VecDeque<&'a str>) {
+        let char_indices: Vec<(usize, char)> = sentence.char_indices().collect();
+        let dag = self.dag(sentence, &char_indices);
+        let route = self.calc(sentence, &char_indices, &dag);
+        let mut x = 0;
+        let mut run: Option<(usize, usize)> = None;
+        while x < char_indices.len() {
+            let y = route[x].1 + 1;
+            let l_indices = &char_indices[x..y];
+            if l_indices.len() == 1 {
+                run = Some(match run {
+                    Some((s, _)) => (s, y),
+                    None => (x, y),
+                });
+            } else {
+                self.flush_hmm_range(sentence, &char_indices, run.take(), queue);
+                let (start, end) = (
+                    char_indices[x].0,
+                    if y < char_indices.len() { char_indices[y].0 } else { sentence.len() },
+                );
+                queue.push_back(&sentence[start..end]);
+            }
+            x = y;
+        }
+        self.flush_hmm_range(sentence, &char_indices, run.take(), queue);
+    }
+
+    /// Lazy, borrowing counterpart of `cut`: yields `&str` slices of
+    /// `sentence` on demand instead of collecting an owned `Vec<String>`
+    /// up front, which matters for callers streaming large documents.
+    /// Equivalent to `cut`, minus the allocation: `cut` could be written as
+    /// `cut_iter(sentence, hmm).map(str::to_owned).collect()`.
+    pub fn cut_iter<'a>(&'a self, sentence: &'a str, hmm: bool) -> Cut<'a> {
+        Cut {
+            jieba: self,
+            sentence,
+            finder: RE_HAN_DEFAULT.captures_iter(sentence),
+            last: 0,
+            done: false,
+            hmm,
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Tokenizes `sentence`, annotating each token with its exact UTF-8
+    /// byte offsets in the original text so callers can drive markup over
+    /// the source string. `TokenizeMode::Search` additionally emits the
+    /// dictionary-covered sub-spans of every word longer than two
+    /// characters, the same way `cut_for_search`-style modes do.
+    pub fn tokenize<'a>(&'a self, sentence: &'a str, mode: TokenizeMode, hmm: bool) -> Vec<Token<'a>> {
+        let mut tokens = Vec::new();
+        for word in self.cut_iter(sentence, hmm) {
+            let start = word.as_ptr() as usize - sentence.as_ptr() as usize;
+            if mode == TokenizeMode::Search {
+                self.push_search_subtokens(word, start, &mut tokens);
+            }
+            tokens.push(Token { word, start, end: start + word.len() });
+        }
+        tokens
+    }
+
+    fn push_search_subtokens<'a>(&self, word: &'a str, word_start: usize, tokens: &mut Vec<Token<'a>>) {
+        let char_indices: Vec<(usize, char)> = word.char_indices().collect();
+        let char_count = char_indices.len();
+        if char_count <= 2 {
+            return;
+        }
+        for gram_len in 2..char_count {
+            for i in 0..=char_count - gram_len {
+                let byte_start = char_indices[i].0;
+                let byte_end = if i + gram_len < char_count {
+                    char_indices[i + gram_len].0
+                } else {
+                    word.len()
+                };
+                let gram = &word[byte_start..byte_end];
+                if self.freq.get(gram).is_some_and(|&freq| freq > 0) {
+                    tokens.push(Token {
+                        word: gram,
+                        start: word_start + byte_start,
+                        end: word_start + byte_end,
+                    });
+                }
+            }
+        }
+    }
+
     pub fn cut(&self, sentence: &str, hmm: bool) -> Vec<String> {
         let mut words = Vec::new();
         let splitter = SplitCaptures::new(&RE_HAN_DEFAULT, sentence);
@@ -341,12 +631,193 @@ impl Jieba {
         }
         words
     }
+
+    fn tag_word(&self, word: String) -> Tag {
+        let tag = self.tags.get(&word).cloned().unwrap_or_else(|| "x".to_string());
+        Tag { word, tag }
+    }
+
+    fn cut_dag_no_hmm_tag(&self, sentence: &str) -> Vec<Tag> {
+        let char_indices: Vec<(usize, char)> = sentence.char_indices().collect();
+        let dag = self.dag(sentence, &char_indices);
+        let route = self.calc(sentence, &char_indices, &dag);
+        let mut tags = Vec::new();
+        let mut x = 0;
+        let mut buf = String::new();
+        while x < char_indices.len() {
+            let y = route[x].1 + 1;
+            let l_indices = &char_indices[x..y];
+            if l_indices.len() == 1 && l_indices.iter().all(|ch| ch.1.is_ascii_alphanumeric()) {
+                buf.push(l_indices[0].1);
+            } else {
+                if !buf.is_empty() {
+                    tags.push(self.tag_word(buf.clone()));
+                    buf.clear();
+                }
+                tags.push(self.tag_word(l_indices.iter().map(|ch| ch.1).collect()));
+            }
+            x = y;
+        }
+        if !buf.is_empty() {
+            tags.push(self.tag_word(buf.clone()));
+            buf.clear();
+        }
+        tags
+    }
+
+    fn flush_hmm_tag_buf(&self, buf: &mut String, tags: &mut Vec<Tag>) {
+        if buf.is_empty() {
+            return;
+        }
+        if buf.chars().count() == 1 {
+            tags.push(self.tag_word(buf.clone()));
+        } else if self.freq.get(buf.as_str()).is_none() {
+            tags.extend(postag::tag(buf).into_iter().map(|(word, tag)| Tag { word, tag }));
+        } else {
+            for chr in buf.chars() {
+                tags.push(self.tag_word(chr.to_string()));
+            }
+        }
+        buf.clear();
+    }
+
+    fn cut_dag_hmm_tag(&self, sentence: &str) -> Vec<Tag> {
+        let char_indices: Vec<(usize, char)> = sentence.char_indices().collect();
+        let dag = self.dag(sentence, &char_indices);
+        let route = self.calc(sentence, &char_indices, &dag);
+        let mut tags = Vec::new();
+        let mut x = 0;
+        let mut buf = String::new();
+        while x < char_indices.len() {
+            let y = route[x].1 + 1;
+            let l_indices = &char_indices[x..y];
+            if l_indices.len() == 1 {
+                buf.push(l_indices[0].1);
+            } else {
+                self.flush_hmm_tag_buf(&mut buf, &mut tags);
+                tags.push(self.tag_word(l_indices.iter().map(|ch| ch.1).collect()));
+            }
+            x = y;
+        }
+        self.flush_hmm_tag_buf(&mut buf, &mut tags);
+        tags
+    }
+
+    /// Part-of-speech tags `sentence`, layering a dictionary-tag lookup for
+    /// known words over the same dictionary segmentation `cut` uses.
+    /// Out-of-vocabulary spans are segmented and tagged by `postag::tag`'s
+    /// HMM pass rather than by `hmm::cut`, so their word boundaries can
+    /// differ from what `cut` returns.
+    pub fn tag(&self, sentence: &str, hmm: bool) -> Vec<Tag> {
+        let mut tags = Vec::new();
+        let splitter = SplitCaptures::new(&RE_HAN_DEFAULT, sentence);
+        for state in splitter {
+            let block = state.as_str();
+            if block.is_empty() {
+                continue;
+            }
+            if RE_HAN_DEFAULT.is_match(block) {
+                if hmm {
+                    tags.extend(self.cut_dag_hmm_tag(block));
+                } else {
+                    tags.extend(self.cut_dag_no_hmm_tag(block));
+                }
+            } else {
+                let skip_splitter = SplitCaptures::new(&RE_SKIP_DEAFULT, block);
+                for skip_state in skip_splitter {
+                    let x = skip_state.as_str();
+                    if x.is_empty() {
+                        continue;
+                    }
+                    if RE_SKIP_DEAFULT.is_match(x) {
+                        tags.push(Tag { word: x.to_string(), tag: "x".to_string() });
+                    } else {
+                        let mut buf = [0; 4];
+                        for chr in x.chars() {
+                            let w = chr.encode_utf8(&mut buf);
+                            let pos = if chr.is_ascii_digit() {
+                                "m"
+                            } else if chr.is_ascii_alphabetic() {
+                                "eng"
+                            } else {
+                                "x"
+                            };
+                            tags.push(Tag { word: w.to_string(), tag: pos.to_string() });
+                        }
+                    }
+                }
+            }
+        }
+        tags
+    }
+
+    /// Inserts `word` into the dictionary at runtime, registering its
+    /// prefix fragments exactly as `load_dict` does and keeping
+    /// `self.total` consistent so the DAG picks the word up on the very
+    /// next `cut`/`tag`/`tokenize` call. When `freq` is `None`, it defaults
+    /// to whatever [`Jieba::suggest_freq`] estimates is needed to keep
+    /// `word` from being split by the rest of the dictionary.
+    pub fn add_word(&mut self, word: &str, freq: Option<usize>, tag: Option<&str>) {
+        let freq = freq.unwrap_or_else(|| self.suggest_freq(&[word]));
+        let old_freq = self.freq.get(word).copied().unwrap_or(0);
+        self.total = self.total + freq - old_freq;
+        let id = self.freq.insert(word, freq);
+        if let Some(tag) = tag {
+            self.tags.insert(word.to_string(), tag.to_string());
+        }
+        let (start, _) = self.freq.span(id);
+        let char_indices: Vec<usize> = word.char_indices().map(|x| x.0).collect();
+        if !char_indices.is_empty() {
+            for &index in &char_indices[1..] {
+                self.freq.insert_prefix(start, start + index as u32);
+            }
+        }
+    }
+
+    /// Removes `word` from the dictionary by setting its frequency to 0,
+    /// the same state `load_dict` leaves an unseen prefix fragment in: the
+    /// word stops being picked as a DAG edge, but stays interned so other
+    /// words sharing its prefix are unaffected.
+    pub fn del_word(&mut self, word: &str) {
+        self.add_word(word, Some(0), None);
+    }
+
+    /// Estimates the frequency `segment.concat()` would need so that, once
+    /// added via [`Jieba::add_word`], `calc`'s route maximization treats it
+    /// the way `segment` implies: a single already-known word is tuned to
+    /// resist being split further by the current dictionary, while
+    /// multiple separate words are tuned to keep the joined phrase from
+    /// outscoring cutting them apart.
+    pub fn suggest_freq(&self, segment: &[&str]) -> usize {
+        let word: String = segment.concat();
+        if segment.len() <= 1 {
+            let words = self.cut_dag_no_hmm(&word);
+            let parts: Vec<&str> = words.iter().map(String::as_str).collect();
+            let estimate = self.product_freq(&parts);
+            estimate.max(self.freq.get(&word).copied().unwrap_or(0) + 1)
+        } else {
+            let estimate = self.product_freq(segment);
+            estimate.min(self.freq.get(&word).copied().unwrap_or(0))
+        }
+    }
+
+    /// The frequency `parts` would collectively need as a single word to
+    /// match the combined probability of cutting them separately.
+    fn product_freq(&self, parts: &[&str]) -> usize {
+        let logtotal = (self.total as f64).ln();
+        let mut log_freq = 0.0;
+        for &part in parts {
+            let part_freq = self.freq.get(part).copied().unwrap_or(1).max(1);
+            log_freq += (part_freq as f64).ln() - logtotal;
+        }
+        (log_freq + logtotal).exp() as usize
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use smallvec::SmallVec;
-    use super::Jieba;
+    use super::{Jieba, TokenizeMode};
 
     #[test]
     fn test_init_with_default_dict() {
@@ -395,4 +866,94 @@ mod tests {
         let words = jieba.cut("我们中出了一个叛徒", true);
         assert_eq!(words, vec!["我们", "中出", "了", "一个", "叛徒"]);
     }
+
+    #[test]
+    fn test_tag() {
+        let jieba = Jieba::new();
+        let tags = jieba.tag("我们中出了一个叛徒", true);
+        let words: Vec<&str> = tags.iter().map(|t| t.word.as_str()).collect();
+        assert_eq!(words, vec!["我们", "中出", "了", "一个", "叛徒"]);
+    }
+
+    #[test]
+    fn test_cut_iter() {
+        let jieba = Jieba::new();
+        let sentence = "abc网球拍卖会def";
+        let words: Vec<&str> = jieba.cut_iter(sentence, false).collect();
+        assert_eq!(words, jieba.cut(sentence, false));
+
+        let sentence = "我们中出了一个叛徒";
+        let words: Vec<&str> = jieba.cut_iter(sentence, true).collect();
+        assert_eq!(words, jieba.cut(sentence, true));
+
+        // "鎉中" falls through to the HMM as a single out-of-vocabulary run
+        // whose Viterbi path ends in a `B` tag rather than `E`/`S`, which
+        // used to make `cut_iter` drop the trailing fragment `cut` keeps.
+        let sentence = "abc网球拍卖会def鎉中";
+        let words: Vec<&str> = jieba.cut_iter(sentence, true).collect();
+        assert_eq!(words, jieba.cut(sentence, true));
+    }
+
+    #[test]
+    fn test_tokenize_default() {
+        let jieba = Jieba::new();
+        let sentence = "网球拍卖会";
+        let tokens = jieba.tokenize(sentence, TokenizeMode::Default, false);
+        for token in &tokens {
+            assert_eq!(&sentence[token.start..token.end], token.word);
+        }
+        let words: Vec<&str> = tokens.iter().map(|t| t.word).collect();
+        assert_eq!(words, jieba.cut(sentence, false));
+    }
+
+    #[test]
+    fn test_tokenize_hmm_fallback_covers_whole_sentence() {
+        let jieba = Jieba::new();
+        // "鎉中" is an out-of-vocabulary run whose HMM Viterbi path ends in
+        // `B` rather than `E`/`S`; the emitted tokens must still tile the
+        // sentence with no trailing gap.
+        let sentence = "abc网球拍卖会def鎉中";
+        let tokens = jieba.tokenize(sentence, TokenizeMode::Default, true);
+        let mut expected_start = 0;
+        for token in &tokens {
+            assert_eq!(token.start, expected_start);
+            assert_eq!(&sentence[token.start..token.end], token.word);
+            expected_start = token.end;
+        }
+        assert_eq!(expected_start, sentence.len());
+    }
+
+    #[test]
+    fn test_add_word() {
+        let mut jieba = Jieba::new();
+        let before_freq = jieba.freq.get("叛徒拍卖会").copied().unwrap_or(0);
+        let before_total = jieba.total;
+        jieba.add_word("叛徒拍卖会", Some(1000), None);
+        assert_eq!(jieba.freq.get("叛徒拍卖会"), Some(&1000));
+        assert_eq!(jieba.total, before_total + 1000 - before_freq);
+        let words = jieba.cut_dag_no_hmm("叛徒拍卖会");
+        assert_eq!(words, vec!["叛徒拍卖会"]);
+    }
+
+    #[test]
+    fn test_del_word() {
+        let mut jieba = Jieba::new();
+        jieba.del_word("网球");
+        assert_eq!(jieba.freq.get("网球"), Some(&0));
+        let words = jieba.cut_dag_no_hmm("网球拍卖会");
+        assert_ne!(words, vec!["网球", "拍卖会"]);
+    }
+
+    #[test]
+    fn test_suggest_freq() {
+        let jieba = Jieba::new();
+        // A single unknown word should get a non-zero suggested frequency,
+        // high enough to keep `add_word` from being split again.
+        let freq = jieba.suggest_freq(&["网球拍卖会"]);
+        assert!(freq > 0);
+        // Forcing two already-known words to stay split should never
+        // suggest more than their combined frequency already implies.
+        let split_freq = jieba.suggest_freq(&["网球", "拍卖会"]);
+        assert!(split_freq <= freq);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader};
+
+use hmm;
+
+static DEFAULT_NER_MODEL: &str = include_str!("data/ner_model.txt");
+
+/// Category assigned to a recognized `Entity`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+    Person,
+    Location,
+    Organization,
+}
+
+/// A named-entity span recognized in a sentence, with byte offsets into
+/// the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entity {
+    pub text: String,
+    pub kind: EntityKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tag {
+    BPer,
+    MPer,
+    EPer,
+    SPer,
+    BLoc,
+    MLoc,
+    ELoc,
+    SLoc,
+    BOrg,
+    MOrg,
+    EOrg,
+    SOrg,
+    O,
+}
+
+const TAGS: [Tag; 13] = [
+    Tag::BPer, Tag::MPer, Tag::EPer, Tag::SPer,
+    Tag::BLoc, Tag::MLoc, Tag::ELoc, Tag::SLoc,
+    Tag::BOrg, Tag::MOrg, Tag::EOrg, Tag::SOrg,
+    Tag::O,
+];
+
+impl Tag {
+    fn from_str(s: &str) -> Option<Tag> {
+        match s {
+            "B-PER" => Some(Tag::BPer),
+            "M-PER" => Some(Tag::MPer),
+            "E-PER" => Some(Tag::EPer),
+            "S-PER" => Some(Tag::SPer),
+            "B-LOC" => Some(Tag::BLoc),
+            "M-LOC" => Some(Tag::MLoc),
+            "E-LOC" => Some(Tag::ELoc),
+            "S-LOC" => Some(Tag::SLoc),
+            "B-ORG" => Some(Tag::BOrg),
+            "M-ORG" => Some(Tag::MOrg),
+            "E-ORG" => Some(Tag::EOrg),
+            "S-ORG" => Some(Tag::SOrg),
+            "O" => Some(Tag::O),
+            _ => None,
+        }
+    }
+
+    fn category(self) -> Option<EntityKind> {
+        match self {
+            Tag::BPer | Tag::MPer | Tag::EPer | Tag::SPer => Some(EntityKind::Person),
+            Tag::BLoc | Tag::MLoc | Tag::ELoc | Tag::SLoc => Some(EntityKind::Location),
+            Tag::BOrg | Tag::MOrg | Tag::EOrg | Tag::SOrg => Some(EntityKind::Organization),
+            Tag::O => None,
+        }
+    }
+
+    /// Whether this tag opens a new entity span (`B-*`/`S-*`), as opposed
+    /// to continuing one (`M-*`/`E-*`).
+    fn starts_entity(self) -> bool {
+        matches!(
+            self,
+            Tag::BPer | Tag::SPer | Tag::BLoc | Tag::SLoc | Tag::BOrg | Tag::SOrg
+        )
+    }
+}
+
+/// Named-entity recognizer built on the same Viterbi core as `hmm::cut`,
+/// but tagging characters with a BMES/entity-category cross product
+/// instead of plain word boundaries.
+#[derive(Debug)]
+pub struct Ner {
+    start: HashMap<Tag, f64>,
+    trans: HashMap<(Tag, Tag), f64>,
+    emit: HashMap<(Tag, char), f64>,
+}
+
+impl Default for Ner {
+    fn default() -> Self {
+        Ner::new()
+    }
+}
+
+impl Ner {
+    pub fn new() -> Self {
+        let mut ner = Ner {
+            start: HashMap::new(),
+            trans: HashMap::new(),
+            emit: HashMap::new(),
+        };
+        let mut default_model = BufReader::new(DEFAULT_NER_MODEL.as_bytes());
+        ner.load_model(&mut default_model).unwrap();
+        ner
+    }
+
+    /// Loads emission/transition/initial-state probabilities from a model
+    /// file, replacing any previously loaded tables. The format is line
+    /// oriented: `START tag logprob`, `TRANS from to logprob` and
+    /// `EMIT tag char logprob`, mirroring how `Jieba::load_dict` reads
+    /// `word freq` pairs.
+    pub fn load_model<R: BufRead>(&mut self, model: &mut R) -> io::Result<()> {
+        self.start.clear();
+        self.trans.clear();
+        self.emit.clear();
+        let mut buf = String::new();
+        while model.read_line(&mut buf)? > 0 {
+            {
+                let line = buf.trim();
+                if !line.is_empty() {
+                    let parts: Vec<&str> = line.split(' ').collect();
+                    match parts[0] {
+                        "START" => {
+                            if let Some(tag) = Tag::from_str(parts[1]) {
+                                self.start.insert(tag, parts[2].parse().unwrap());
+                            }
+                        }
+                        "TRANS" => {
+                            if let (Some(from), Some(to)) = (Tag::from_str(parts[1]), Tag::from_str(parts[2])) {
+                                self.trans.insert((from, to), parts[3].parse().unwrap());
+                            }
+                        }
+                        "EMIT" => {
+                            if let Some(tag) = Tag::from_str(parts[1]) {
+                                let chr = parts[2].chars().next().unwrap();
+                                self.emit.insert((tag, chr), parts[3].parse().unwrap());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            buf.clear();
+        }
+        Ok(())
+    }
+
+    /// Recognizes named-entity spans in `sentence`, returning exact byte
+    /// offsets into the input.
+    pub fn recognize(&self, sentence: &str) -> Vec<Entity> {
+        let char_indices: Vec<(usize, char)> = sentence.char_indices().collect();
+        if char_indices.is_empty() {
+            return Vec::new();
+        }
+        let chars: Vec<char> = char_indices.iter().map(|&(_, c)| c).collect();
+        let path = hmm::viterbi(&chars, &TAGS, &self.start, &self.trans, &self.emit);
+
+        let mut entities = Vec::new();
+        let mut i = 0;
+        while i < path.len() {
+            match path[i].category() {
+                None => i += 1,
+                Some(kind) => {
+                    let start_byte = char_indices[i].0;
+                    let mut j = i;
+                    while j + 1 < path.len()
+                        && path[j + 1].category() == Some(kind)
+                        && !path[j + 1].starts_entity()
+                    {
+                        j += 1;
+                    }
+                    let end_byte = if j + 1 < char_indices.len() {
+                        char_indices[j + 1].0
+                    } else {
+                        sentence.len()
+                    };
+                    entities.push(Entity {
+                        text: sentence[start_byte..end_byte].to_string(),
+                        kind,
+                        start: start_byte,
+                        end: end_byte,
+                    });
+                    i = j + 1;
+                }
+            }
+        }
+        entities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ner, Tag};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_init_with_default_model() {
+        let _ = Ner::new();
+    }
+
+    #[test]
+    fn test_recognize_empty() {
+        let ner = Ner::new();
+        assert_eq!(ner.recognize(""), vec![]);
+    }
+
+    #[test]
+    fn test_recognize_adjacent_same_kind_entities() {
+        // A hand-built model whose Viterbi path on "AAAA" is forced to
+        // [B-LOC, E-LOC, B-LOC, E-LOC]: two adjacent two-character Location
+        // spans back to back, with no `O` tag between them. Grouping
+        // purely by category would collapse these into one "AAAA" span.
+        let mut start = HashMap::new();
+        start.insert(Tag::BLoc, -1.0);
+
+        let mut trans = HashMap::new();
+        trans.insert((Tag::BLoc, Tag::ELoc), -1.0);
+        trans.insert((Tag::ELoc, Tag::BLoc), -1.0);
+
+        let mut emit = HashMap::new();
+        emit.insert((Tag::BLoc, 'A'), -1.0);
+        emit.insert((Tag::ELoc, 'A'), -1.0);
+
+        let ner = Ner { start, trans, emit };
+        let entities = ner.recognize("AAAA");
+        let locations: Vec<&str> = entities
+            .iter()
+            .filter(|e| e.kind == super::EntityKind::Location)
+            .map(|e| e.text.as_str())
+            .collect();
+        assert_eq!(locations, vec!["AA", "AA"]);
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+use hmm;
+
+static DEFAULT_POS_MODEL: &str = include_str!("data/pos_model.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Category {
+    N,
+    V,
+    A,
+    D,
+    X,
+}
+
+impl Category {
+    fn from_str(s: &str) -> Option<Category> {
+        match s {
+            "n" => Some(Category::N),
+            "v" => Some(Category::V),
+            "a" => Some(Category::A),
+            "d" => Some(Category::D),
+            "x" => Some(Category::X),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Category::N => "n",
+            Category::V => "v",
+            Category::A => "a",
+            Category::D => "d",
+            Category::X => "x",
+        }
+    }
+}
+
+const CATEGORIES: [Category; 5] = [Category::N, Category::V, Category::A, Category::D, Category::X];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tag {
+    B(Category),
+    M(Category),
+    E(Category),
+    S(Category),
+}
+
+impl Tag {
+    fn from_str(s: &str) -> Option<Tag> {
+        let mut parts = s.splitn(2, '-');
+        let position = parts.next()?;
+        let category = Category::from_str(parts.next()?)?;
+        match position {
+            "B" => Some(Tag::B(category)),
+            "M" => Some(Tag::M(category)),
+            "E" => Some(Tag::E(category)),
+            "S" => Some(Tag::S(category)),
+            _ => None,
+        }
+    }
+}
+
+fn all_tags() -> Vec<Tag> {
+    let mut tags = Vec::with_capacity(CATEGORIES.len() * 4);
+    for &c in &CATEGORIES {
+        tags.push(Tag::B(c));
+        tags.push(Tag::M(c));
+        tags.push(Tag::E(c));
+        tags.push(Tag::S(c));
+    }
+    tags
+}
+
+type Model = (HashMap<Tag, f64>, HashMap<(Tag, Tag), f64>, HashMap<(Tag, char), f64>);
+
+fn parse_model(data: &str) -> Model {
+    let mut start = HashMap::new();
+    let mut trans = HashMap::new();
+    let mut emit = HashMap::new();
+    for line in data.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let parts: Vec<&str> = line.split(' ').collect();
+        match parts[0] {
+            "START" => {
+                if let Some(tag) = Tag::from_str(parts[1]) {
+                    start.insert(tag, parts[2].parse().unwrap());
+                }
+            }
+            "TRANS" => {
+                if let (Some(from), Some(to)) = (Tag::from_str(parts[1]), Tag::from_str(parts[2])) {
+                    trans.insert((from, to), parts[3].parse().unwrap());
+                }
+            }
+            "EMIT" => {
+                if let Some(tag) = Tag::from_str(parts[1]) {
+                    let chr = parts[2].chars().next().unwrap();
+                    emit.insert((tag, chr), parts[3].parse().unwrap());
+                }
+            }
+            _ => {}
+        }
+    }
+    (start, trans, emit)
+}
+
+lazy_static! {
+    static ref ALL_TAGS: Vec<Tag> = all_tags();
+    static ref MODEL: Model = parse_model(DEFAULT_POS_MODEL);
+}
+
+/// Tags an out-of-vocabulary buffer with a second, POS-specific Viterbi
+/// pass over the same `hmm::viterbi` core used for word-segmentation
+/// fallback, grouping the resulting BMES run into `(word, tag)` pairs.
+pub(crate) fn tag(buf: &str) -> Vec<(String, String)> {
+    let chars: Vec<char> = buf.chars().collect();
+    let path = hmm::viterbi(&chars, &ALL_TAGS, &MODEL.0, &MODEL.1, &MODEL.2);
+
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (&chr, &tag) in chars.iter().zip(path.iter()) {
+        match tag {
+            Tag::B(_) => {
+                if !word.is_empty() {
+                    words.push((word.clone(), Category::X.as_str().to_string()));
+                    word.clear();
+                }
+                word.push(chr);
+            }
+            Tag::M(_) => word.push(chr),
+            Tag::E(c) => {
+                word.push(chr);
+                words.push((word.clone(), c.as_str().to_string()));
+                word.clear();
+            }
+            Tag::S(c) => {
+                if !word.is_empty() {
+                    words.push((word.clone(), c.as_str().to_string()));
+                    word.clear();
+                }
+                words.push((chr.to_string(), c.as_str().to_string()));
+            }
+        }
+    }
+    if !word.is_empty() {
+        words.push((word, Category::X.as_str().to_string()));
+    }
+    words
+}